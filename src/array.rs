@@ -1,8 +1,11 @@
 use std::ops::{Add, Div, Mul, Sub};
-use ndarray::{Array, ArrayD, Dimension, Ix1, IxDyn, Array1, Ix2, Array2, Axis};
+use ndarray::{Array, ArrayD, Dimension, Ix1, IxDyn, Array1, Ix2, Array2, Array3, ArrayView2, Axis};
+use ndarray::Slice as NdSlice;
 use ndarray::linalg::Dot;
+use gemm::{gemm, Parallelism};
 use num_traits::{Num, NumCast};
-use crate::array::CpuArray::{F32Array, F64Array};
+use num_complex::Complex32;
+use crate::array::CpuArray::{F32Array, F64Array, C32Array};
 use crate::DType;
 use crate::DType::F32;
 
@@ -10,6 +13,175 @@ pub enum CpuArray
 {
     F32Array(ArrayD<f32>),
     F64Array(ArrayD<f64>),
+    C32Array(ArrayD<Complex32>),
+}
+
+/// A NumPy-style per-axis slice specifier: `start`/`stop` may be negative to
+/// count from the end of the axis, `stop` of `None` runs to the end, and
+/// `step` keeps every k-th element.
+#[derive(Clone, Copy)]
+pub struct Slice {
+    pub start: isize,
+    pub stop: Option<isize>,
+    pub step: isize,
+}
+
+impl Slice {
+    /// Lower `start`/`stop` against a concrete axis `len`, normalizing negative
+    /// indices and clamping out-of-range bounds, then hand back an
+    /// [`ndarray::Slice`].
+    fn to_ndarray_slice(&self, len: usize) -> NdSlice {
+        let len = len as isize;
+        let normalize = |idx: isize| -> isize {
+            let idx = if idx < 0 { idx + len } else { idx };
+            idx.clamp(0, len)
+        };
+        let start = normalize(self.start);
+        let stop = self.stop.map(normalize).unwrap_or(len);
+        NdSlice::new(start, Some(stop), self.step)
+    }
+}
+
+/// Compute the NumPy-style broadcast shape of two operands.
+///
+/// The shapes are aligned by their trailing dimensions and the shorter one is
+/// padded with leading 1s. Each axis must then have equal extents or one of
+/// them must be 1, in which case the output takes the larger extent. Returns
+/// `None` when the shapes are not broadcast-compatible.
+fn broadcast_shape(lhs: &[usize], rhs: &[usize]) -> Option<Vec<usize>> {
+    let ndim = lhs.len().max(rhs.len());
+    let mut shape = vec![0usize; ndim];
+    for i in 0..ndim {
+        let l = lhs.len().checked_sub(ndim - i).map_or(1, |idx| lhs[idx]);
+        let r = rhs.len().checked_sub(ndim - i).map_or(1, |idx| rhs[idx]);
+        shape[i] = match (l, r) {
+            (l, r) if l == r => l,
+            (1, r) => r,
+            (l, 1) => l,
+            _ => return None,
+        };
+    }
+    Some(shape)
+}
+
+/// Multiply two 2-D matrices with the `gemm` crate, reading strides straight
+/// from the `ndarray` views so neither operand is cloned. Rayon parallelism is
+/// enabled once the problem is large enough to amortise the thread hand-off.
+fn gemm_2d(a: &ArrayView2<f32>, b: &ArrayView2<f32>) -> Result<Array2<f32>, String> {
+    let (m, k) = (a.shape()[0], a.shape()[1]);
+    let (kb, n) = (b.shape()[0], b.shape()[1]);
+    if k != kb {
+        return Err(format!("Cannot MatMul: inner dimensions {} and {} do not match", k, kb));
+    }
+
+    let mut out = Array2::<f32>::zeros((m, n));
+    let (a_rs, a_cs) = (a.strides()[0], a.strides()[1]);
+    let (b_rs, b_cs) = (b.strides()[0], b.strides()[1]);
+    let (o_rs, o_cs) = (out.strides()[0], out.strides()[1]);
+
+    let parallelism = if m * n * k >= 1 << 16 {
+        Parallelism::Rayon(0)
+    } else {
+        Parallelism::None
+    };
+
+    unsafe {
+        gemm(
+            m, n, k,
+            out.as_mut_ptr(), o_cs, o_rs,
+            false,
+            a.as_ptr(), a_cs, a_rs,
+            b.as_ptr(), b_cs, b_rs,
+            0.0f32, 1.0f32,
+            false, false, false,
+            parallelism,
+        );
+    }
+
+    Ok(out)
+}
+
+/// Batched matmul for rank > 2 operands: all but the final two axes are treated
+/// as batch dimensions, broadcast between `lhs` and `rhs`, and the 2-D gemm is
+/// run over each batch slice into a preallocated output.
+fn batched_matmul(lhs: &ArrayD<f32>, rhs: &ArrayD<f32>) -> Result<ArrayD<f32>, String> {
+    let (ld, rd) = (lhs.ndim(), rhs.ndim());
+    if ld < 2 || rd < 2 {
+        return Err(String::from("Cannot MatMul: batched matmul requires both operands to be at least 2-D"));
+    }
+    let batch = broadcast_shape(&lhs.shape()[..ld - 2], &rhs.shape()[..rd - 2])
+        .ok_or_else(|| String::from("Cannot MatMul: batch dimensions are not broadcast-compatible"))?;
+
+    let (m, k) = (lhs.shape()[ld - 2], lhs.shape()[ld - 1]);
+    let (kb, n) = (rhs.shape()[rd - 2], rhs.shape()[rd - 1]);
+    if k != kb {
+        return Err(format!("Cannot MatMul: inner dimensions {} and {} do not match", k, kb));
+    }
+
+    let n_batches: usize = batch.iter().product();
+
+    let l_shape: Vec<usize> = batch.iter().copied().chain([m, k]).collect();
+    let r_shape: Vec<usize> = batch.iter().copied().chain([kb, n]).collect();
+    let lb = lhs.broadcast(IxDyn(&l_shape))
+        .ok_or_else(|| String::from("Cannot MatMul: lhs not broadcastable to batch shape"))?
+        .to_owned().into_shape((n_batches, m, k)).map_err(|e| e.to_string())?;
+    let rb = rhs.broadcast(IxDyn(&r_shape))
+        .ok_or_else(|| String::from("Cannot MatMul: rhs not broadcastable to batch shape"))?
+        .to_owned().into_shape((n_batches, kb, n)).map_err(|e| e.to_string())?;
+
+    let mut out = Array3::<f32>::zeros((n_batches, m, n));
+    for batch_idx in 0..n_batches {
+        let a = lb.index_axis(Axis(0), batch_idx);
+        let b = rb.index_axis(Axis(0), batch_idx);
+        out.index_axis_mut(Axis(0), batch_idx).assign(&gemm_2d(&a, &b)?);
+    }
+
+    let out_shape: Vec<usize> = batch.iter().copied().chain([m, n]).collect();
+    out.into_shape(out_shape).map(|a| a.into_dyn()).map_err(|e| e.to_string())
+}
+
+/// In-place radix-2 Cooley–Tukey FFT over a single lane of length `n`, which
+/// must be a power of two. The input is first reordered by bit-reversal, then
+/// `log2(n)` butterfly stages combine neighbouring halves. `ifft` uses the
+/// conjugate twiddle and the caller scales the result by `1/n`.
+fn fft_1d(data: &mut [Complex32], inverse: bool) {
+    let n = data.len();
+
+    // Bit-reversal permutation of the indices.
+    let mut j = 0usize;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    // Butterfly stages: stage `s` works on sub-transforms of length `m = 2^s`.
+    let sign = if inverse { 1.0f32 } else { -1.0f32 };
+    let mut m = 2usize;
+    while m <= n {
+        let theta = sign * 2.0 * std::f32::consts::PI / m as f32;
+        let w_m = Complex32::new(theta.cos(), theta.sin());
+        let half = m / 2;
+        let mut k = 0;
+        while k < n {
+            let mut w = Complex32::new(1.0, 0.0);
+            for jj in 0..half {
+                let t = w * data[k + jj + half];
+                let u = data[k + jj];
+                data[k + jj] = u + t;
+                data[k + jj + half] = u - t;
+                w *= w_m;
+            }
+            k += m;
+        }
+        m <<= 1;
+    }
 }
 
 impl CpuArray
@@ -38,7 +210,8 @@ impl CpuArray
     pub fn add(&self, rhs: &Self) -> Option<Self> {
         match (self, rhs) {
             (F32Array(ref a), F32Array(ref b)) => {
-                Some(F32Array(a.add(b)))
+                let shape = broadcast_shape(a.shape(), b.shape())?;
+                Some(F32Array(&a.broadcast(shape.clone())? + &b.broadcast(shape)?))
             }
             _ => None
         }
@@ -47,7 +220,8 @@ impl CpuArray
     pub fn sub(&self, rhs: &Self) -> Option<Self> {
         match (self, rhs) {
             (F32Array(ref a), F32Array(ref b)) => {
-                Some(F32Array(a.sub(b)))
+                let shape = broadcast_shape(a.shape(), b.shape())?;
+                Some(F32Array(&a.broadcast(shape.clone())? - &b.broadcast(shape)?))
             }
             _ => None
         }
@@ -55,7 +229,8 @@ impl CpuArray
     pub fn mul(&self, rhs: &Self) -> Option<Self> {
         match (self, rhs) {
             (F32Array(ref a), F32Array(ref b)) => {
-                Some(F32Array(a.mul(b)))
+                let shape = broadcast_shape(a.shape(), b.shape())?;
+                Some(F32Array(&a.broadcast(shape.clone())? * &b.broadcast(shape)?))
             }
             _ => None
         }
@@ -63,7 +238,8 @@ impl CpuArray
     pub fn div(&self, rhs: &Self) -> Option<Self> {
         match (self, rhs) {
             (F32Array(ref a), F32Array(ref b)) => {
-                Some(F32Array(a.div(b)))
+                let shape = broadcast_shape(a.shape(), b.shape())?;
+                Some(F32Array(&a.broadcast(shape.clone())? / &b.broadcast(shape)?))
             }
             _ => None
         }
@@ -71,14 +247,18 @@ impl CpuArray
 
     pub fn matmul(&self, rhs: &Self) -> Result<Self, String> {
         match (self, rhs) {
-            (F32Array(l), F32Array(r)) if l.ndim() == 1 && l.ndim() == 1 => {
+            (F32Array(l), F32Array(r)) if l.ndim() == 1 && r.ndim() == 1 => {
                 let res = l.clone().into_dimensionality::<Ix1>().unwrap().dot(&r.clone().into_dimensionality::<Ix1>().unwrap());
                 let wrapped = F32Array(Array1::from_vec(vec![res]).into_dyn());
                 Ok(wrapped)
             },
-            (F32Array(l), F32Array(r)) if l.ndim() == 2 && l.ndim() == 2 => {
-                let res = l.clone().into_dimensionality::<Ix2>().unwrap().dot(&r.clone().into_dimensionality::<Ix2>().unwrap());
-                Ok(F32Array(res.into_dyn()))
+            (F32Array(l), F32Array(r)) if l.ndim() == 2 && r.ndim() == 2 => {
+                let a = l.view().into_dimensionality::<Ix2>().unwrap();
+                let b = r.view().into_dimensionality::<Ix2>().unwrap();
+                Ok(F32Array(gemm_2d(&a, &b)?.into_dyn()))
+            }
+            (F32Array(l), F32Array(r)) if l.ndim() > 2 || r.ndim() > 2 => {
+                batched_matmul(l, r).map(F32Array)
             }
             _ => Err(String::from("Cannot MatMul for the provided data types"))
         }
@@ -129,6 +309,97 @@ impl CpuArray
         }
     }
 
+    /// Elementwise negation.
+    pub fn neg(&self) -> Result<Self, String> {
+        match self {
+            F32Array(ref arr) => Ok(F32Array(arr.mapv(|x| -x))),
+            _ => Err(String::from("Cannot negate for the provided data types")),
+        }
+    }
+
+    /// The `x > 0` mask as `1.0`/`0.0`, used for the local gradient of ReLU.
+    pub fn relu_mask(&self) -> Result<Self, String> {
+        match self {
+            F32Array(ref arr) => Ok(F32Array(arr.mapv(|x| if x > 0.0 { 1.0 } else { 0.0 }))),
+            _ => Err(String::from("Cannot ReLU-mask for the provided data types")),
+        }
+    }
+
+    /// Broadcast this array up to `shape`, following the same trailing-axis
+    /// rules as the elementwise ops. Returns `None` when the shapes are not
+    /// broadcast-compatible.
+    pub fn broadcast_to(&self, shape: Vec<usize>) -> Option<Self> {
+        match self {
+            F32Array(ref arr) => Some(F32Array(arr.broadcast(shape)?.to_owned())),
+            _ => None,
+        }
+    }
+
+    /// Reinterpret the array under a new shape with the same element count.
+    pub fn reshape(&self, shape: Vec<usize>) -> Result<Self, String> {
+        match self {
+            F32Array(ref arr) => arr.clone().into_shape(shape).map(F32Array).map_err(|e| e.to_string()),
+            _ => Err(String::from("Cannot reshape for the provided data types")),
+        }
+    }
+
+    /// Discrete Fourier transform along `axis` using a radix-2 FFT. The extent
+    /// along `axis` must be a power of two; otherwise an error is returned.
+    pub fn fft(&self, axis: usize) -> Result<Self, String> {
+        self.transform(axis, false)
+    }
+
+    /// Inverse discrete Fourier transform along `axis`. Uses the conjugate
+    /// twiddle factor and scales the result by `1/n`.
+    pub fn ifft(&self, axis: usize) -> Result<Self, String> {
+        self.transform(axis, true)
+    }
+
+    /// Shared driver for [`fft`](Self::fft)/[`ifft`](Self::ifft): transform every
+    /// lane along `axis` in place, dividing by `n` on the inverse pass.
+    fn transform(&self, axis: usize, inverse: bool) -> Result<Self, String> {
+        match self {
+            C32Array(arr) => {
+                if axis >= arr.ndim() {
+                    return Err(format!("Cannot FFT: axis {} out of range for {}-D array", axis, arr.ndim()));
+                }
+                let n = arr.shape()[axis];
+                if !n.is_power_of_two() {
+                    return Err(format!("Cannot FFT: axis length {} is not a power of two", n));
+                }
+
+                let mut out = arr.clone();
+                for mut lane in out.lanes_mut(Axis(axis)) {
+                    let mut buf: Vec<Complex32> = lane.iter().copied().collect();
+                    fft_1d(&mut buf, inverse);
+                    if inverse {
+                        let scale = 1.0 / n as f32;
+                        for v in buf.iter_mut() {
+                            *v *= scale;
+                        }
+                    }
+                    for (dst, src) in lane.iter_mut().zip(buf) {
+                        *dst = src;
+                    }
+                }
+                Ok(C32Array(out))
+            }
+            _ => Err(String::from("Cannot FFT for the provided data types")),
+        }
+    }
+
+    /// Lift a real `F32Array` into the complex domain (zero imaginary part) and
+    /// run the forward FFT along `axis`.
+    pub fn fft_real(&self, axis: usize) -> Result<Self, String> {
+        match self {
+            F32Array(arr) => {
+                let complex = arr.mapv(|x| Complex32::new(x, 0.0));
+                C32Array(complex).fft(axis)
+            }
+            _ => Err(String::from("Cannot FFT: fft_real expects a real F32Array")),
+        }
+    }
+
     pub fn get<T: Num + Copy + NumCast>(&self, index: Vec<usize>) -> Option<T> {
         let val = match self {
             F32Array(arr) => arr.get(IxDyn(&index)).cloned(),
@@ -140,6 +411,38 @@ impl CpuArray
         }
     }
 
+    /// Select a sub-array with one [`Slice`] specifier per axis.
+    ///
+    /// Negative `start`/`stop` count from the end of the axis and are
+    /// normalized against its length; a `step` of `k` keeps every k-th
+    /// element; out-of-range bounds clamp rather than panic.
+    pub fn slice(&self, specs: &[Slice]) -> Option<Self> {
+        match self {
+            F32Array(arr) => {
+                if specs.len() != arr.ndim() {
+                    return None;
+                }
+                let lengths: Vec<usize> = arr.shape().to_vec();
+                let view = arr.slice_each_axis(|ax| {
+                    specs[ax.axis.index()].to_ndarray_slice(lengths[ax.axis.index()])
+                });
+                Some(F32Array(view.to_owned()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Pick arbitrary indices along `axis`, producing a new array of the
+    /// selected rows/columns/etc.
+    pub fn select(&self, axis: usize, indices: &[usize]) -> Option<Self> {
+        match self {
+            F32Array(arr) if axis < arr.ndim() => {
+                Some(F32Array(arr.select(Axis(axis), indices)))
+            }
+            _ => None,
+        }
+    }
+
     pub fn copy_from(&mut self, other: &Self) {
         match (self, other) {
             (F32Array(arr1), F32Array(arr2)) => arr1.assign(arr2),
@@ -151,6 +454,7 @@ impl CpuArray
         match self {
             F32Array(arr) => arr.shape().to_vec(),
             F64Array(arr) => arr.shape().to_vec(),
+            C32Array(arr) => arr.shape().to_vec(),
         }
     }
 }
@@ -214,4 +518,77 @@ mod tests {
         assert_eq!(sum.shape(), vec![2]);
         assert_eq!(sum.get(vec![0]), Some(60f32));
     }
+
+    #[test]
+    fn test_slice(){
+        use crate::array::Slice;
+        let arr = F32Array(Array::from_shape_vec(IxDyn(&vec![3, 4]), (0..12).map(|x| x as f32).collect()).unwrap());
+        // Rows 1..3, every other column starting at 1 -> [[5, 7], [9, 11]]
+        let sliced = arr.slice(&[
+            Slice { start: 1, stop: None, step: 1 },
+            Slice { start: 1, stop: None, step: 2 },
+        ]).unwrap();
+        assert_eq!(sliced.shape(), vec![2, 2]);
+        assert_eq!(sliced.get(vec![0, 0]), Some(5f32));
+        assert_eq!(sliced.get(vec![1, 1]), Some(11f32));
+    }
+
+    #[test]
+    fn test_slice_negative(){
+        use crate::array::Slice;
+        let arr = F32Array(Array::from_shape_vec(IxDyn(&vec![5]), (0..5).map(|x| x as f32).collect()).unwrap());
+        // Negative start counts from the end, out-of-range stop clamps.
+        let sliced = arr.slice(&[Slice { start: -2, stop: Some(100), step: 1 }]).unwrap();
+        assert_eq!(sliced.shape(), vec![2]);
+        assert_eq!(sliced.get(vec![0]), Some(3f32));
+        assert_eq!(sliced.get(vec![1]), Some(4f32));
+    }
+
+    #[test]
+    fn test_fft_real(){
+        use crate::array::CpuArray::C32Array;
+        // FFT of a constant signal concentrates all energy in the DC bin.
+        let arr = F32Array(Array::from_shape_vec(IxDyn(&vec![4]), vec![1.0f32, 1.0, 1.0, 1.0]).unwrap());
+        let spectrum = arr.fft_real(0).unwrap();
+        if let C32Array(out) = spectrum {
+            assert_eq!(out.shape(), &[4]);
+            assert!((out[[0]].re - 4.0).abs() < 1e-5);
+            assert!(out[[0]].im.abs() < 1e-5);
+            for k in 1..4 {
+                assert!(out[[k]].norm() < 1e-5);
+            }
+        } else {
+            panic!("fft_real should yield a complex array");
+        }
+    }
+
+    #[test]
+    fn test_fft_ifft_roundtrip(){
+        use crate::array::CpuArray::C32Array;
+        let arr = F32Array(Array::from_shape_vec(IxDyn(&vec![8]), (0..8).map(|x| x as f32).collect()).unwrap());
+        let recovered = arr.fft_real(0).unwrap().ifft(0).unwrap();
+        if let C32Array(out) = recovered {
+            for k in 0..8 {
+                assert!((out[[k]].re - k as f32).abs() < 1e-4);
+                assert!(out[[k]].im.abs() < 1e-4);
+            }
+        } else {
+            panic!("ifft should yield a complex array");
+        }
+    }
+
+    #[test]
+    fn test_fft_non_power_of_two(){
+        let arr = F32Array(Array::from_shape_vec(IxDyn(&vec![3]), vec![1.0f32, 2.0, 3.0]).unwrap());
+        assert!(arr.fft_real(0).is_err());
+    }
+
+    #[test]
+    fn test_select(){
+        let arr = F32Array(Array::from_shape_vec(IxDyn(&vec![4, 2]), (0..8).map(|x| x as f32).collect()).unwrap());
+        let picked = arr.select(0, &[0, 2]).unwrap();
+        assert_eq!(picked.shape(), vec![2, 2]);
+        assert_eq!(picked.get(vec![0, 0]), Some(0f32));
+        assert_eq!(picked.get(vec![1, 1]), Some(5f32));
+    }
 }
\ No newline at end of file
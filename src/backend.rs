@@ -1,7 +1,7 @@
 use std::ops::Deref;
 use std::sync::RwLockReadGuard;
 use num_traits::{Num, NumCast};
-use crate::array::{CpuArray};
+use crate::array::{CpuArray, Slice};
 use crate::backend::BackendData::{Metal, Cpu};
 use crate::DType;
 use crate::tensor::Data;
@@ -18,6 +18,24 @@ pub enum BackendData
     Metal,
 }
 
+impl BackendData {
+    /// Select a sub-array with one [`Slice`] specifier per axis.
+    pub fn slice(&self, specs: &[Slice]) -> Option<Self> {
+        match self {
+            Cpu(arr) => arr.slice(specs).map(Cpu),
+            _ => None,
+        }
+    }
+
+    /// Pick arbitrary indices along `axis`.
+    pub fn select(&self, axis: usize, indices: &[usize]) -> Option<Self> {
+        match self {
+            Cpu(arr) => arr.select(axis, indices).map(Cpu),
+            _ => None,
+        }
+    }
+}
+
 impl Data for BackendData {
     fn zeros(&self, shape: Vec<usize>, dtype: DType) -> Self {
         match self {
@@ -28,7 +46,7 @@ impl Data for BackendData {
 
     fn ones(&self, shape: Vec<usize>, dtype: DType) -> Self {
         match self {
-            Cpu(_) => Cpu(CpuArray::zeros(shape, dtype)),
+            Cpu(_) => Cpu(CpuArray::ones(shape, dtype)),
             Metal => Metal
         }
     }
@@ -87,7 +105,10 @@ impl Data for BackendData {
     }
 
     fn sum(&self, dims: Vec<usize>) -> Result<Self, String> {
-        todo!()
+        match self {
+            Cpu(tensor) => Ok(Cpu((*tensor).sum(dims)?)),
+            _ => Err(String::from("Could not sum for provided backend type"))
+        }
     }
 
     fn transpose(&self) -> Result<Self, String> {
@@ -97,6 +118,34 @@ impl Data for BackendData {
         }
     }
 
+    fn neg(&self) -> Result<Self, String> {
+        match self {
+            Cpu(tensor) => Ok(Cpu((*tensor).neg()?)),
+            _ => Err(String::from("Could not negate for provided backend type"))
+        }
+    }
+
+    fn relu_mask(&self) -> Result<Self, String> {
+        match self {
+            Cpu(tensor) => Ok(Cpu((*tensor).relu_mask()?)),
+            _ => Err(String::from("Could not ReLU-mask for provided backend type"))
+        }
+    }
+
+    fn broadcast_to(&self, shape: Vec<usize>) -> Option<Self> {
+        match self {
+            Cpu(tensor) => tensor.broadcast_to(shape).map(Cpu),
+            _ => None
+        }
+    }
+
+    fn reshape(&self, shape: Vec<usize>) -> Result<Self, String> {
+        match self {
+            Cpu(tensor) => Ok(Cpu((*tensor).reshape(shape)?)),
+            _ => Err(String::from("Could not reshape for provided backend type"))
+        }
+    }
+
     fn shape(&self) -> Vec<usize> {
         match self {
             Cpu(tensor) => tensor.shape(),
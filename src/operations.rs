@@ -6,5 +6,95 @@ pub enum Op {
     Sub(Tensor, Tensor),
     Mul(Tensor, Tensor),
     Div(Tensor, Tensor),
+    MatMul(Tensor, Tensor),
+    ReLU(Tensor),
+    Sum(Tensor, Vec<usize>),
+    Transpose(Tensor),
     None
-}
\ No newline at end of file
+}
+
+impl Op {
+    /// The operands recorded by this op, in the order their gradients are
+    /// returned by [`Op::backward`].
+    pub fn operands(&self) -> Vec<&Tensor> {
+        match self {
+            Op::Add(lhs, rhs)
+            | Op::Sub(lhs, rhs)
+            | Op::Mul(lhs, rhs)
+            | Op::Div(lhs, rhs)
+            | Op::MatMul(lhs, rhs) => vec![lhs, rhs],
+            Op::ReLU(x) | Op::Transpose(x) => vec![x],
+            Op::Sum(x, _) => vec![x],
+            Op::None => vec![],
+        }
+    }
+
+    /// Propagate the upstream gradient `grad` through the local derivative of
+    /// this op, returning one gradient per operand (matching [`Op::operands`]).
+    ///
+    /// Gradients that were broadcast during the forward pass are reduced back
+    /// to each operand's original shape before being returned.
+    pub fn backward(&self, grad: &Tensor) -> Vec<Tensor> {
+        match self {
+            Op::Add(lhs, rhs) => vec![
+                reduce_to(grad.clone(), &lhs.shape()),
+                reduce_to(grad.clone(), &rhs.shape()),
+            ],
+            Op::Sub(lhs, rhs) => vec![
+                reduce_to(grad.clone(), &lhs.shape()),
+                reduce_to(grad.neg(), &rhs.shape()),
+            ],
+            Op::Mul(lhs, rhs) => vec![
+                reduce_to(grad.mul(rhs), &lhs.shape()),
+                reduce_to(grad.mul(lhs), &rhs.shape()),
+            ],
+            Op::Div(lhs, rhs) => vec![
+                reduce_to(grad.div(rhs), &lhs.shape()),
+                reduce_to(grad.mul(lhs).div(rhs).div(rhs).neg(), &rhs.shape()),
+            ],
+            Op::MatMul(lhs, rhs) => vec![
+                grad.matmul(&rhs.transpose()),
+                lhs.transpose().matmul(grad),
+            ],
+            Op::ReLU(x) => vec![grad.mul(&x.relu_mask())],
+            Op::Sum(x, dims) => {
+                // Re-insert the reduced axes as size-1 so the grad lines up with
+                // the input before broadcasting back to its full shape. An empty
+                // `dims` means a full reduction, so every axis collapses to 1.
+                let full = x.shape();
+                let mut unsqueezed = full.clone();
+                if dims.is_empty() {
+                    unsqueezed.iter_mut().for_each(|e| *e = 1);
+                } else {
+                    for &d in dims {
+                        unsqueezed[d] = 1;
+                    }
+                }
+                vec![grad.reshape(unsqueezed).broadcast_to(&full)]
+            }
+            Op::Transpose(_) => vec![grad.transpose()],
+            Op::None => vec![],
+        }
+    }
+}
+
+/// Sum `grad` over the axes that were broadcast to turn `shape` into
+/// `grad`'s shape, yielding a gradient that matches `shape`.
+fn reduce_to(grad: Tensor, shape: &[usize]) -> Tensor {
+    let grad_shape = grad.shape();
+    if grad_shape == shape {
+        return grad;
+    }
+
+    // Axes that only exist in `grad` (leading broadcast dims) plus axes whose
+    // extent was 1 in the original operand both need summing back out.
+    let offset = grad_shape.len() - shape.len();
+    let mut axes: Vec<usize> = (0..offset).collect();
+    for (axis, &extent) in shape.iter().enumerate() {
+        if extent == 1 && grad_shape[offset + axis] != 1 {
+            axes.push(offset + axis);
+        }
+    }
+
+    grad.sum(axes).reshape(shape.to_vec())
+}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+
+use memmap2::Mmap;
+use safetensors::tensor::{Dtype, SafeTensors, TensorView};
+use safetensors::serialize_to_file;
+
+use crate::array::CpuArray;
+use crate::array::CpuArray::{F32Array, F64Array, C32Array};
+use crate::DType;
+
+/// Map a Mantis [`DType`] onto the corresponding `safetensors` dtype.
+fn to_st_dtype(dtype: DType) -> Dtype {
+    match dtype {
+        DType::F32 => Dtype::F32,
+        DType::F64 => Dtype::F64,
+    }
+}
+
+/// Flatten an array's elements into a little-endian, row-major byte buffer.
+/// Complex arrays have no `safetensors` representation and are rejected.
+fn to_le_bytes(arr: &CpuArray) -> Result<(DType, Vec<usize>, Vec<u8>), String> {
+    match arr {
+        F32Array(a) => Ok((
+            DType::F32,
+            a.shape().to_vec(),
+            a.iter().flat_map(|x| x.to_le_bytes()).collect(),
+        )),
+        F64Array(a) => Ok((
+            DType::F64,
+            a.shape().to_vec(),
+            a.iter().flat_map(|x| x.to_le_bytes()).collect(),
+        )),
+        C32Array(_) => Err(String::from("safetensors: C32 arrays cannot be serialized")),
+    }
+}
+
+/// Lower a named collection of arrays into the owned `(name, dtype, shape,
+/// bytes)` tuples that the borrowed [`TensorView`]s read from.
+fn to_raw(tensors: &HashMap<String, CpuArray>) -> Result<Vec<(String, (DType, Vec<usize>, Vec<u8>))>, String> {
+    tensors
+        .iter()
+        .map(|(name, arr)| to_le_bytes(arr).map(|bytes| (name.clone(), bytes)))
+        .collect()
+}
+
+/// Borrow the lowered buffers as `safetensors` tensor views.
+fn to_views(raw: &[(String, (DType, Vec<usize>, Vec<u8>))]) -> Result<Vec<(&str, TensorView)>, String> {
+    raw.iter()
+        .map(|(name, (dtype, shape, bytes))| {
+            TensorView::new(to_st_dtype(*dtype), shape.clone(), bytes)
+                .map(|view| (name.as_str(), view))
+                .map_err(|e| e.to_string())
+        })
+        .collect()
+}
+
+/// Serialize a named collection of arrays into a `safetensors` byte buffer.
+pub fn save(tensors: &HashMap<String, CpuArray>) -> Result<Vec<u8>, String> {
+    let raw = to_raw(tensors)?;
+    let views = to_views(&raw)?;
+    safetensors::serialize(views, &None).map_err(|e| e.to_string())
+}
+
+/// Serialize a named collection of arrays straight to a `.safetensors` file.
+pub fn save_to_file<P: AsRef<Path>>(tensors: &HashMap<String, CpuArray>, path: P) -> Result<(), String> {
+    let raw = to_raw(tensors)?;
+    let views = to_views(&raw)?;
+    serialize_to_file(views, &None, path.as_ref()).map_err(|e| e.to_string())
+}
+
+/// Reconstruct a single [`CpuArray`] from a parsed `safetensors` view,
+/// validating that the declared byte length matches `shape × dtype size`.
+fn view_to_array(view: &TensorView) -> Result<CpuArray, String> {
+    let shape = view.shape().to_vec();
+    let numel: usize = shape.iter().product();
+    let bytes = view.data();
+
+    match view.dtype() {
+        Dtype::F32 => {
+            if bytes.len() != numel * 4 {
+                return Err(format!(
+                    "safetensors: byte length {} does not match {} f32 elements",
+                    bytes.len(), numel
+                ));
+            }
+            let data: Vec<f32> = bytes
+                .chunks_exact(4)
+                .map(|c| f32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+                .collect();
+            ndarray::ArrayD::from_shape_vec(shape, data)
+                .map(F32Array)
+                .map_err(|e| e.to_string())
+        }
+        Dtype::F64 => {
+            if bytes.len() != numel * 8 {
+                return Err(format!(
+                    "safetensors: byte length {} does not match {} f64 elements",
+                    bytes.len(), numel
+                ));
+            }
+            let data: Vec<f64> = bytes
+                .chunks_exact(8)
+                .map(|c| f64::from_le_bytes([c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]]))
+                .collect();
+            ndarray::ArrayD::from_shape_vec(shape, data)
+                .map(F64Array)
+                .map_err(|e| e.to_string())
+        }
+        other => Err(format!("safetensors: unsupported dtype {:?}", other)),
+    }
+}
+
+/// Parse a `safetensors` byte buffer into a named collection of arrays.
+pub fn load(buffer: &[u8]) -> Result<HashMap<String, CpuArray>, String> {
+    let tensors = SafeTensors::deserialize(buffer).map_err(|e| e.to_string())?;
+    tensors
+        .tensors()
+        .iter()
+        .map(|(name, view)| view_to_array(view).map(|arr| (name.clone(), arr)))
+        .collect()
+}
+
+/// Memory-map a `.safetensors` file and parse it into a named collection of
+/// arrays, pairing with [`save_to_file`].
+pub fn load_from_file<P: AsRef<Path>>(path: P) -> Result<HashMap<String, CpuArray>, String> {
+    let file = File::open(path).map_err(|e| e.to_string())?;
+    // Safe in practice for a read-only mapping we own for the duration of the
+    // parse; the elements are copied into owned arrays before it is dropped.
+    let mmap = unsafe { Mmap::map(&file).map_err(|e| e.to_string())? };
+    load(&mmap)
+}
@@ -0,0 +1,236 @@
+use std::sync::{Arc, RwLock};
+use num_traits::{Num, NumCast};
+use crate::backend::BackendData;
+use crate::operations::Op;
+use crate::DType;
+
+/// Backend-agnostic array operations. Every concrete backend (`BackendData`
+/// over a CPU `ndarray`, a future Metal buffer, …) implements this trait so the
+/// `Tensor` layer can stay oblivious to where the data lives.
+pub trait Data {
+    fn zeros(&self, shape: Vec<usize>, dtype: DType) -> Self;
+    fn ones(&self, shape: Vec<usize>, dtype: DType) -> Self;
+    fn add(&self, rhs: &Self) -> Option<Self> where Self: Sized;
+    fn sub(&self, rhs: &Self) -> Option<Self> where Self: Sized;
+    fn mul(&self, rhs: &Self) -> Option<Self> where Self: Sized;
+    fn div(&self, rhs: &Self) -> Option<Self> where Self: Sized;
+    fn matmul(&self, rhs: &Self) -> Result<Self, String> where Self: Sized;
+    fn relu(&self) -> Result<Self, String> where Self: Sized;
+    fn exp(&self) -> Result<Self, String> where Self: Sized;
+    fn sum(&self, dims: Vec<usize>) -> Result<Self, String> where Self: Sized;
+    fn transpose(&self) -> Result<Self, String> where Self: Sized;
+    fn neg(&self) -> Result<Self, String> where Self: Sized;
+    fn relu_mask(&self) -> Result<Self, String> where Self: Sized;
+    fn broadcast_to(&self, shape: Vec<usize>) -> Option<Self> where Self: Sized;
+    fn reshape(&self, shape: Vec<usize>) -> Result<Self, String> where Self: Sized;
+    fn shape(&self) -> Vec<usize>;
+    fn get<T: Num + Copy + NumCast>(&self, index: Vec<usize>) -> Option<T>;
+    fn copy_from(&mut self, other: &Self) -> ();
+}
+
+/// The mutable interior of a [`Tensor`]: the backing buffer, the dtype it was
+/// created with, the [`Op`] that produced it (`Op::None` for leaves), and the
+/// gradient accumulated by [`Tensor::backward`].
+struct TensorInner {
+    data: BackendData,
+    dtype: DType,
+    op: Op,
+    grad: Option<Tensor>,
+}
+
+/// A reference-counted tensor that records the op that produced it so the graph
+/// can be walked backwards for reverse-mode autodiff.
+#[derive(Clone)]
+pub struct Tensor {
+    inner: Arc<RwLock<TensorInner>>,
+}
+
+impl Tensor {
+    /// Wrap a backend buffer as a leaf tensor (no producing op).
+    pub fn new(data: BackendData, dtype: DType) -> Self {
+        Tensor::from_op(data, dtype, Op::None)
+    }
+
+    /// Wrap a backend buffer as the output of `op`.
+    fn from_op(data: BackendData, dtype: DType, op: Op) -> Self {
+        Tensor { inner: Arc::new(RwLock::new(TensorInner { data, dtype, op, grad: None })) }
+    }
+
+    pub fn shape(&self) -> Vec<usize> {
+        self.inner.read().unwrap().data.shape()
+    }
+
+    pub fn dtype(&self) -> DType {
+        self.inner.read().unwrap().dtype
+    }
+
+    /// The op that produced this tensor.
+    fn op(&self) -> Op {
+        self.inner.read().unwrap().op.clone()
+    }
+
+    /// The gradient accumulated by the most recent [`backward`](Self::backward),
+    /// if any.
+    pub fn grad(&self) -> Option<Tensor> {
+        self.inner.read().unwrap().grad.clone()
+    }
+
+    pub fn get<T: Num + Copy + NumCast>(&self, index: Vec<usize>) -> Option<T> {
+        self.inner.read().unwrap().data.get(index)
+    }
+
+    pub fn add(&self, rhs: &Tensor) -> Tensor {
+        let out = {
+            let (a, b) = (self.inner.read().unwrap(), rhs.inner.read().unwrap());
+            a.data.add(&b.data).expect("broadcast-incompatible shapes in Tensor::add")
+        };
+        Tensor::from_op(out, self.dtype(), Op::Add(self.clone(), rhs.clone()))
+    }
+
+    pub fn sub(&self, rhs: &Tensor) -> Tensor {
+        let out = {
+            let (a, b) = (self.inner.read().unwrap(), rhs.inner.read().unwrap());
+            a.data.sub(&b.data).expect("broadcast-incompatible shapes in Tensor::sub")
+        };
+        Tensor::from_op(out, self.dtype(), Op::Sub(self.clone(), rhs.clone()))
+    }
+
+    pub fn mul(&self, rhs: &Tensor) -> Tensor {
+        let out = {
+            let (a, b) = (self.inner.read().unwrap(), rhs.inner.read().unwrap());
+            a.data.mul(&b.data).expect("broadcast-incompatible shapes in Tensor::mul")
+        };
+        Tensor::from_op(out, self.dtype(), Op::Mul(self.clone(), rhs.clone()))
+    }
+
+    pub fn div(&self, rhs: &Tensor) -> Tensor {
+        let out = {
+            let (a, b) = (self.inner.read().unwrap(), rhs.inner.read().unwrap());
+            a.data.div(&b.data).expect("broadcast-incompatible shapes in Tensor::div")
+        };
+        Tensor::from_op(out, self.dtype(), Op::Div(self.clone(), rhs.clone()))
+    }
+
+    pub fn matmul(&self, rhs: &Tensor) -> Tensor {
+        let out = {
+            let (a, b) = (self.inner.read().unwrap(), rhs.inner.read().unwrap());
+            a.data.matmul(&b.data).expect("shape error in Tensor::matmul")
+        };
+        Tensor::from_op(out, self.dtype(), Op::MatMul(self.clone(), rhs.clone()))
+    }
+
+    pub fn relu(&self) -> Tensor {
+        let out = self.inner.read().unwrap().data.relu().expect("ReLU failed in Tensor::relu");
+        Tensor::from_op(out, self.dtype(), Op::ReLU(self.clone()))
+    }
+
+    pub fn sum(&self, dims: Vec<usize>) -> Tensor {
+        let out = self.inner.read().unwrap().data.sum(dims.clone()).expect("sum failed in Tensor::sum");
+        Tensor::from_op(out, self.dtype(), Op::Sum(self.clone(), dims))
+    }
+
+    pub fn transpose(&self) -> Tensor {
+        let out = self.inner.read().unwrap().data.transpose().expect("transpose failed in Tensor::transpose");
+        Tensor::from_op(out, self.dtype(), Op::Transpose(self.clone()))
+    }
+
+    pub fn neg(&self) -> Tensor {
+        let out = self.inner.read().unwrap().data.neg().expect("negation failed in Tensor::neg");
+        Tensor::new(out, self.dtype())
+    }
+
+    /// The `x > 0` mask, used as the local gradient of [`relu`](Self::relu).
+    pub fn relu_mask(&self) -> Tensor {
+        let out = self.inner.read().unwrap().data.relu_mask().expect("ReLU-mask failed in Tensor::relu_mask");
+        Tensor::new(out, self.dtype())
+    }
+
+    pub fn broadcast_to(&self, shape: &[usize]) -> Tensor {
+        let out = self.inner.read().unwrap().data.broadcast_to(shape.to_vec())
+            .expect("broadcast-incompatible shape in Tensor::broadcast_to");
+        Tensor::new(out, self.dtype())
+    }
+
+    pub fn reshape(&self, shape: Vec<usize>) -> Tensor {
+        let out = self.inner.read().unwrap().data.reshape(shape).expect("reshape failed in Tensor::reshape");
+        Tensor::new(out, self.dtype())
+    }
+
+    /// Overwrite the accumulated gradient.
+    fn set_grad(&self, grad: Tensor) {
+        self.inner.write().unwrap().grad = Some(grad);
+    }
+
+    /// Add `grad` into the accumulated gradient, summing when one already
+    /// exists so gradients from multiple consumers combine.
+    fn accumulate_grad(&self, grad: Tensor) {
+        let combined = match self.grad() {
+            Some(existing) => existing.add(&grad),
+            None => grad,
+        };
+        self.set_grad(combined);
+    }
+
+    /// Post-order traversal of the graph rooted at `self`: every node appears
+    /// after all of its operands, so iterating in reverse visits consumers
+    /// before producers.
+    fn topo_order(&self) -> Vec<Tensor> {
+        let mut visited: Vec<*const RwLock<TensorInner>> = Vec::new();
+        let mut order: Vec<Tensor> = Vec::new();
+        self.build_topo(&mut visited, &mut order);
+        order
+    }
+
+    fn build_topo(&self, visited: &mut Vec<*const RwLock<TensorInner>>, order: &mut Vec<Tensor>) {
+        let ptr = Arc::as_ptr(&self.inner);
+        if visited.contains(&ptr) {
+            return;
+        }
+        visited.push(ptr);
+        let op = self.op();
+        for operand in op.operands() {
+            operand.build_topo(visited, order);
+        }
+        order.push(self.clone());
+    }
+
+    /// Clear any gradient stored on this tensor.
+    pub fn zero_grad(&self) {
+        self.inner.write().unwrap().grad = None;
+    }
+
+    /// Reverse-mode autodiff. Clears any gradients left over from a previous
+    /// pass, seeds this tensor's gradient with ones, walks the graph in
+    /// reverse-topological order, and accumulates each operand's gradient via
+    /// its op's local rules.
+    pub fn backward(&self) {
+        let order = self.topo_order();
+
+        // Start from a clean slate so repeated calls don't double-count.
+        for node in &order {
+            node.zero_grad();
+        }
+
+        let ones = {
+            let inner = self.inner.read().unwrap();
+            inner.data.ones(inner.data.shape(), inner.dtype)
+        };
+        self.set_grad(Tensor::new(ones, self.dtype()));
+
+        for node in order.iter().rev() {
+            let (op, grad) = {
+                let inner = node.inner.read().unwrap();
+                (inner.op.clone(), inner.grad.clone())
+            };
+            let grad = match grad {
+                Some(g) => g,
+                None => continue,
+            };
+            let operands = op.operands();
+            let input_grads = op.backward(&grad);
+            for (operand, input_grad) in operands.into_iter().zip(input_grads) {
+                operand.accumulate_grad(input_grad);
+            }
+        }
+    }
+}